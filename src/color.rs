@@ -0,0 +1,104 @@
+//! Perceptual RGB/HSV color helpers used by `SN3193Driver::set_color`/`set_color_hsv`.
+//!
+//! Human brightness perception is non-linear, so feeding raw 0-255 values straight into the PWM
+//! registers makes fades and mixed colors bunch up near the top of the range. [`GAMMA_TABLE`] maps
+//! a perceptual input to the PWM duty that actually looks linear, using the CIE 1931 lightness
+//! formula.
+
+/// Whether a color passed to `set_color`/`set_color_hsv` is treated as a perceptual value and
+/// corrected to PWM duty, or written directly as duty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GammaCorrection {
+    /// Apply the CIE 1931 perceptual correction in [`GAMMA_TABLE`] before writing PWM duty.
+    Corrected,
+    /// Write the given values directly as PWM duty, with no correction.
+    Raw,
+}
+
+/// Perceptual-to-PWM-duty lookup table.
+///
+/// For input `x`, `L* = 100 * x/255`, then `Y = ((L* + 16)/116)^3` when `L* > 8`, else
+/// `Y = L*/903.3`; the table entry is `Y` scaled to 0-255 and rounded.
+#[rustfmt::skip]
+pub(crate) const GAMMA_TABLE: [u8; 256] = [
+    0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 3, 3, 3, 3, 3, 3, 3, 3, 4,
+    4, 4, 4, 4, 4, 5, 5, 5, 5, 5, 6, 6, 6, 6, 6, 7,
+    7, 7, 7, 8, 8, 8, 8, 9, 9, 9, 10, 10, 10, 10, 11, 11,
+    11, 12, 12, 12, 13, 13, 13, 14, 14, 15, 15, 15, 16, 16, 17, 17,
+    17, 18, 18, 19, 19, 20, 20, 21, 21, 22, 22, 23, 23, 24, 24, 25,
+    25, 26, 26, 27, 28, 28, 29, 29, 30, 31, 31, 32, 32, 33, 34, 34,
+    35, 36, 37, 37, 38, 39, 39, 40, 41, 42, 43, 43, 44, 45, 46, 47,
+    47, 48, 49, 50, 51, 52, 53, 54, 54, 55, 56, 57, 58, 59, 60, 61,
+    62, 63, 64, 65, 66, 67, 68, 70, 71, 72, 73, 74, 75, 76, 77, 79,
+    80, 81, 82, 83, 85, 86, 87, 88, 90, 91, 92, 94, 95, 96, 98, 99,
+    100, 102, 103, 105, 106, 108, 109, 110, 112, 113, 115, 116, 118, 120, 121, 123,
+    124, 126, 128, 129, 131, 132, 134, 136, 138, 139, 141, 143, 145, 146, 148, 150,
+    152, 154, 155, 157, 159, 161, 163, 165, 167, 169, 171, 173, 175, 177, 179, 181,
+    183, 185, 187, 189, 191, 193, 196, 198, 200, 202, 204, 207, 209, 211, 214, 216,
+    218, 220, 223, 225, 228, 230, 232, 235, 237, 240, 242, 245, 247, 250, 252, 255,
+];
+
+/// Apply the gamma correction table to a single channel.
+pub(crate) fn gamma_correct(value: u8) -> u8 {
+    GAMMA_TABLE[value as usize]
+}
+
+/// Convert an HSV color (`h` in degrees, wrapped to 0..360; `s`/`v` in 0..=255) to RGB.
+pub(crate) fn hsv_to_rgb(h: u16, s: u8, v: u8) -> (u8, u8, u8) {
+    if s == 0 {
+        return (v, v, v);
+    }
+
+    let h = h % 360;
+    let region = h / 60;
+    let remainder = ((h % 60) * 255) / 60;
+
+    let s = s as u32;
+    let v = v as u32;
+    let remainder = remainder as u32;
+
+    let p = (v * (255 - s) / 255) as u8;
+    let q = (v * (255 * 255 - s * remainder) / (255 * 255)) as u8;
+    let t = (v * (255 * 255 - s * (255 - remainder)) / (255 * 255)) as u8;
+    let v = v as u8;
+
+    match region {
+        0 => (v, t, p),
+        1 => (q, v, p),
+        2 => (p, v, t),
+        3 => (p, q, v),
+        4 => (t, p, v),
+        _ => (v, p, q),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gamma_table_endpoints() {
+        assert_eq!(gamma_correct(0), 0);
+        assert_eq!(gamma_correct(255), 255);
+    }
+
+    #[test]
+    fn test_gamma_table_is_monotonic() {
+        for window in GAMMA_TABLE.windows(2) {
+            assert!(window[1] >= window[0]);
+        }
+    }
+
+    #[test]
+    fn test_hsv_to_rgb_primaries() {
+        assert_eq!(hsv_to_rgb(0, 255, 255), (255, 0, 0));
+        assert_eq!(hsv_to_rgb(120, 255, 255), (0, 255, 0));
+        assert_eq!(hsv_to_rgb(240, 255, 255), (0, 0, 255));
+    }
+
+    #[test]
+    fn test_hsv_to_rgb_zero_saturation_is_gray() {
+        assert_eq!(hsv_to_rgb(180, 0, 128), (128, 128, 128));
+    }
+}
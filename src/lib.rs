@@ -29,21 +29,25 @@
 //! ```
 //! ## Features
 //! ### PWM mode
-//! The driver can set the LEDs to PWM mode. This allows you to set the brightness of each LED individually.
+//! The driver starts out, and `init()` returns, in the `mode::Pwm` state. This allows you to set
+//! the brightness of each LED individually.
 //! ```rust
-//! if let Err(e) = diver.set_led_mode(LEDModeSettings::PWM) {
-//!    panic!("Error setting LED mode to PWM: {:?}", e);
-//! }
 //! // check your device wiring to know which LED is which
 //! if let Err(e) = driver.set_pwm_levels(255, 128, 0) {
 //!   panic!("Error setting PWM levels: {:?}", e);
 //! }
 //! ```
 //! ### Breathing mode
-//! The driver can set the LEDs to breathing mode. This mode allows you to set the time it takes for the LED to
+//! The driver can be switched to breathing mode. This mode allows you to set the time it takes for the LED to
 //! ramp up to full brightness, hold at full brightness, ramp down to off, and hold at off. Each of these times
 //! can be set individually for each LED. Furthermore, the PWM levels can be set for each LED.
+//!
+//! `set_led_mode` consumes the driver and returns it in the new mode, so `set_pwm_levels` and
+//! `set_breathing_times_for_led` are only reachable from the state that the chip is actually in -
+//! calling the wrong one for the current mode is a compile error rather than a silently ignored
+//! register write.
 //! ```rust
+//! let mut driver = driver.set_led_mode::<mode::Breathing>()?;
 //! // set the breathing times the same for all LEDs
 //! if let Err(e) = driver.set_breathing_times_for_led(
 //!     LEDId::ALL,
@@ -55,29 +59,70 @@
 //! ) {
 //!    panic!("Error setting breathing times: {:?}", e);
 //! }
-//! // enable breathing mode
-//! if let Err(e) = driver.set_led_mode(LEDModeSettings::Breathing) {
-//!   panic!("Error setting LED mode to breathing: {:?}", e);
-//! }
 //! ```
 //! The PWM levels and breathing times can be changed at any time. The driver will update the LEDs with the new settings.
 //!
+//! By default every PWM/breathing write is immediately followed by the register-update strobe
+//! the chip needs to latch it. When staging many changes at once (e.g. every LED's breathing
+//! times), call `set_auto_update(false)` first and flush once with `flush`/`flush_breathing_times`
+//! to cut bus traffic instead of strobing after each one.
+//!
+//! ### RGB color
+//! `set_color`/`set_color_hsv` treat the three channels as one color. Since human brightness
+//! perception isn't linear, pass `GammaCorrection::Corrected` to run values through a CIE 1931
+//! lookup table first so fades and mixed colors look smooth, or `GammaCorrection::Raw` for direct
+//! duty control.
+//! ```rust
+//! driver.set_color(255, 128, 0, GammaCorrection::Corrected)?;
+//! driver.set_color_hsv(210, 255, 200, GammaCorrection::Corrected)?;
+//! ```
+//! ### Software patterns
+//! For color transitions the chip's breathing mode can't express - fades between arbitrary colors,
+//! rainbow cycling, and so on - build a [`Pattern`] of keyframes and drive it with a [`PatternPlayer`]
+//! from your main loop. This is non-blocking: `step` just reports when a new `set_pwm_levels` write is
+//! needed, so it composes with whatever scheduling the rest of the application already uses instead of
+//! relying on `DelayNs`.
+//! ```rust
+//! let keyframes = [Keyframe::new(255, 0, 0, 0), Keyframe::new(0, 0, 255, 2000)];
+//! let mut player = PatternPlayer::new(Pattern::new(&keyframes, true));
+//!
+//! // in the main loop, with `elapsed_ms` since the last iteration:
+//! if let Some((r, g, b)) = player.step(elapsed_ms) {
+//!     driver.set_pwm_levels(r, g, b)?;
+//! }
+//! ```
 //! ### Function chaining
-//! The driver functions return a `Result` that contains the driver reference in the `Ok` value. This
-//! can be chained together to make the code more readable.
+//! Functions that write to the chip without changing its mode return a `Result` that contains the
+//! driver reference in the `Ok` value. This can be chained together to make the code more readable.
 //! ```rust
-//! driver.set_led_mode(LEDModeSettings::PWM)?
-//!     .set_current(CurrentSettings::Current17p5mA)?
+//! driver.set_current(CurrentSettings::Current17p5mA)?
 //!     .set_pwm_levels(255, 128, 0)?
 //!     .enable_leds(true, true, true)?;
 //! ```
+//! ### Power management
+//! Call `sleep` to put the chip into software shutdown with all channels disabled, saving
+//! current while the LEDs aren't needed, and `wake` to restore normal operation. `wake`
+//! re-applies whatever current/LED-enable/PWM (or whole-strip breathing) configuration was last
+//! set, so callers don't need to re-initialize the driver after a sleep/wake cycle. Dropping the
+//! driver also quiesces the LED channels, so LEDs don't stay lit if the driver goes out of scope.
+//! ```rust
+//! driver.sleep()?;
+//! // ... later ...
+//! driver.wake()?;
+//! ```
 //! ## License
 //! This library is licensed under the MIT license.
 
 #![no_std]
 #![allow(dead_code, clippy::unusual_byte_groupings)]
+use core::marker::PhantomData;
 use embedded_hal::{delay::DelayNs, i2c};
 
+mod color;
+mod pattern;
+pub use color::GammaCorrection;
+pub use pattern::{Keyframe, Pattern, PatternPlayer};
+
 // Registers
 const REGISTER_SHUTDOWN: u8 = 0x00;
 const REGISTER_BREATHING_CONTROL: u8 = 0x01;
@@ -106,7 +151,74 @@ const SHUTDOWN_CHANNEL_DISABLE: u8 = 0b00_0_0000_0;
 const SOFTWARE_SHUTDOWN_MODE: u8 = 0b00_0_0000_0;
 const SOFTWARE_SHUTDOWN_NORMAL: u8 = 0b00_0_0000_1;
 
-#[derive(Debug, PartialEq)]
+/// Marker types used as the `MODE` type parameter of [`SN3193Driver`].
+///
+/// These types never exist at runtime - they only appear as `PhantomData` - but they let the
+/// compiler reject calls to `set_pwm_levels`/`set_breathing_times_for_led` when the driver isn't
+/// known to be in the matching chip mode.
+pub mod mode {
+    /// The driver is in PWM mode. See [`super::SN3193Driver::set_pwm_levels`].
+    #[derive(Debug)]
+    pub struct Pwm;
+
+    /// The driver is in breathing mode. See [`super::SN3193Driver::set_breathing_times_for_led`].
+    #[derive(Debug)]
+    pub struct Breathing;
+}
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for super::mode::Pwm {}
+    impl Sealed for super::mode::Breathing {}
+}
+
+/// A chip mode that [`SN3193Driver::set_led_mode`] can transition the driver into.
+///
+/// This trait is sealed; `mode::Pwm` and `mode::Breathing` are the only implementors.
+pub trait LedMode: private::Sealed {
+    /// The value written to `REGISTER_LED_MODE` to select this mode.
+    #[doc(hidden)]
+    const REGISTER_VALUE: u8;
+}
+
+impl LedMode for mode::Pwm {
+    const REGISTER_VALUE: u8 = 0b00_0_00000;
+}
+
+impl LedMode for mode::Breathing {
+    const REGISTER_VALUE: u8 = 0b00_1_00000;
+}
+
+/// I2C bus address of the SN3193, determined by how the AD pin is wired.
+///
+/// The SN3193 only exposes one address pin, so these four variants are the complete set of
+/// addresses the chip can be wired to respond to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Address {
+    /// AD pin connected to GND.
+    Gnd = 0x68,
+    /// AD pin connected to SCL.
+    Scl = 0x69,
+    /// AD pin connected to SDA.
+    Sda = 0x6A,
+    /// AD pin connected to VDD.
+    Vdd = 0x6B,
+}
+
+impl Address {
+    /// The 7-bit I2C bus address for this AD-pin wiring.
+    pub fn bits(self) -> u8 {
+        self as u8
+    }
+}
+
+impl From<Address> for u8 {
+    fn from(address: Address) -> Self {
+        address.bits()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum CurrentSettings {
     Current42mA = 0b000_000_00,
     Current10mA = 0b000_001_00,
@@ -115,15 +227,6 @@ pub enum CurrentSettings {
     Current17p5mA = 0b000_100_00,
 }
 
-/// LED mode settings
-#[derive(Debug, PartialEq)]
-pub enum LEDModeSettings {
-    /// The LEDs are controlled by PWM settings
-    PWM = 0b00_0_00000,
-    /// The LEDs are controlled by breathing configuration
-    Breathing = 0b00_1_00000,
-}
-
 /// The time it takes to start breathing.
 /// The time is set in the T0 register.
 #[derive(Debug, PartialEq)]
@@ -247,7 +350,11 @@ where
     }
 }
 
-pub struct SN3193Driver<I2C, DELAY>
+/// The mode-independent driver state: the I2C/delay handles plus the bits that need to survive a
+/// `set_led_mode` transition. This is a separate type from [`SN3193Driver`] so that `Drop`
+/// (implemented here, to quiesce the LEDs) doesn't prevent `set_led_mode` from moving the state
+/// into a `SN3193Driver` with a different `MODE`.
+struct DriverState<I2C, DELAY>
 where
     I2C: i2c::I2c,
     DELAY: DelayNs,
@@ -255,81 +362,314 @@ where
     i2c: I2C,
     address: u8,
     delay: DELAY,
+    auto_update: bool,
+    cached_current: Option<CurrentSettings>,
+    cached_led_enable: Option<(bool, bool, bool)>,
+    cached_pwm_levels: Option<(u8, u8, u8)>,
+    /// `(t0, t1t2, t3t4)` bytes from the last `set_breathing_times_for_led(LEDId::ALL, ...)` call.
+    /// Per-LED breathing configurations aren't cached; `wake` only replays whole-strip settings.
+    cached_breathing_bytes: Option<(u8, u8, u8)>,
+}
+
+/// Driver for the SN3193 RGB LED driver chip.
+///
+/// `MODE` tracks which of the chip's mutually-exclusive LED modes the driver is currently in -
+/// either [`mode::Pwm`] (the default, also what `init()` returns) or [`mode::Breathing`]. See the
+/// [module-level docs](crate) for how to move between them with [`SN3193Driver::set_led_mode`].
+pub struct SN3193Driver<I2C, DELAY, MODE = mode::Pwm>
+where
+    I2C: i2c::I2c,
+    DELAY: DelayNs,
+{
+    state: DriverState<I2C, DELAY>,
+    _mode: PhantomData<MODE>,
 }
 
-impl<I2C, DELAY> SN3193Driver<I2C, DELAY>
+impl<I2C, DELAY> SN3193Driver<I2C, DELAY, mode::Pwm>
 where
     I2C: i2c::I2c,
     DELAY: DelayNs,
 {
     /// Default address for the SN3193. This is the address when the AD pin is connected to GND.
     pub fn default_address() -> u8 {
-        0x68
+        Address::Gnd.bits()
     }
 
-    /// Create a new SN3193 driver with the default address (0x68)
+    /// Create a new SN3193 driver with the default address (AD pin connected to GND)
     pub fn new(i2c: I2C, delay: DELAY) -> Self {
-        Self::new_with_address(i2c, delay, Self::default_address())
+        Self::new_with_address(i2c, delay, Address::Gnd)
     }
 
     /// Create a new SN3193 driver with a specific address.
-    /// The address can be changed by connecting the AD pin to GND, VDD, SCL, or SDA.
-    /// The set of available addresses are:
-    /// - 0x68 when AD is connected to GND
-    /// - 0x6B when AD is connected to VDD
-    /// - 0x69 when AD is connected to SCL
-    /// - 0x6A when AD is connected to SDA
-    pub fn new_with_address(i2c: I2C, delay: DELAY, address: u8) -> Self {
+    /// The address is determined by how the AD pin is wired; see [`Address`] for the options.
+    pub fn new_with_address(i2c: I2C, delay: DELAY, address: Address) -> Self {
         Self {
-            i2c,
-            address,
-            delay,
+            state: DriverState {
+                i2c,
+                address: address.bits(),
+                delay,
+                auto_update: true,
+                cached_current: None,
+                cached_led_enable: None,
+                cached_pwm_levels: None,
+                cached_breathing_bytes: None,
+            },
+            _mode: PhantomData,
         }
     }
 
-    /// Get a mutable reference to the I2C bus used by the driver
-    pub fn i2c(&mut self) -> &mut I2C {
-        &mut self.i2c
-    }
-
     /// Initialize the SN3193 driver. This will set the LED mode to PWM, the current to 17.5 mA, and enable all LEDs.
-    pub fn init(&mut self) -> Result<&mut Self, SN3193Error<I2C>> {
+    pub fn init(self) -> Result<Self, SN3193Error<I2C>> {
+        let mut this = self;
+
         // start up sequence
         // wait for power up
-        self.delay.delay_ms(50);
+        this.state.delay.delay_ms(50);
         // reset
-        self.i2c
-            .write(self.address, &[REGISTER_RESET])
+        this.state
+            .i2c
+            .write(this.state.address, &[REGISTER_RESET])
             .map_err(SN3193Error::I2CError)?;
 
-        self.delay.delay_ms(50);
-        self.i2c
+        this.state.delay.delay_ms(50);
+        this.state
+            .i2c
             .write(
-                self.address,
+                this.state.address,
                 &[
                     REGISTER_SHUTDOWN,
-                    SHUTDOWN_CHANNEL_ENABLE | SOFTWARE_SHUTDOWN_MODE,
+                    SHUTDOWN_CHANNEL_ENABLE | SOFTWARE_SHUTDOWN_NORMAL,
                 ],
             )
             .map_err(SN3193Error::I2CError)?;
 
         // set mode 0 (PWM)
-        self.set_led_mode(LEDModeSettings::PWM)?;
+        let mut this = this.set_led_mode::<mode::Pwm>()?;
 
         // set current to 17.5 ma and enable all LEDs
-        self.set_current(CurrentSettings::Current17p5mA)?
+        this.set_current(CurrentSettings::Current17p5mA)?
             .enable_leds(true, true, true)?;
 
+        Ok(this)
+    }
+
+    /// Set the PWM levels for the RGB LED. 255 is full on, 0 is off.
+    ///
+    /// The LED1/LED2/LED3 PWM registers are contiguous and the SN3193 auto-increments its
+    /// register pointer, so all three are written in a single transaction.
+    pub fn set_pwm_levels(
+        &mut self,
+        led1: u8,
+        led2: u8,
+        led3: u8,
+    ) -> Result<&mut Self, SN3193Error<I2C>> {
+        // things seem to work better with a small delay here, but it's not in the datasheet
+        self.state.delay.delay_ms(1);
+        self.state
+            .i2c
+            .write(self.state.address, &[REGISTER_LED1_PWM, led1, led2, led3])
+            .map_err(SN3193Error::I2CError)?;
+        self.state.cached_pwm_levels = Some((led1, led2, led3));
+        self.load_register_data()
+    }
+
+    /// Set the RGB color of the LED, treating the three channels as one color the way the Linux
+    /// `led-class-multicolor` subsystem does. Built on [`SN3193Driver::set_pwm_levels`].
+    ///
+    /// `gamma` selects whether `r`/`g`/`b` are perceptual values that get corrected to PWM duty
+    /// with a CIE 1931 lookup table (`GammaCorrection::Corrected`), or are written directly as
+    /// duty with no correction (`GammaCorrection::Raw`).
+    pub fn set_color(
+        &mut self,
+        r: u8,
+        g: u8,
+        b: u8,
+        gamma: GammaCorrection,
+    ) -> Result<&mut Self, SN3193Error<I2C>> {
+        let (r, g, b) = match gamma {
+            GammaCorrection::Corrected => (
+                color::gamma_correct(r),
+                color::gamma_correct(g),
+                color::gamma_correct(b),
+            ),
+            GammaCorrection::Raw => (r, g, b),
+        };
+        self.set_pwm_levels(r, g, b)
+    }
+
+    /// Set the color of the LED from an HSV triple. `h` is in degrees (wrapped to 0..360), `s`
+    /// and `v` are 0..=255. See [`SN3193Driver::set_color`] for the meaning of `gamma`.
+    pub fn set_color_hsv(
+        &mut self,
+        h: u16,
+        s: u8,
+        v: u8,
+        gamma: GammaCorrection,
+    ) -> Result<&mut Self, SN3193Error<I2C>> {
+        let (r, g, b) = color::hsv_to_rgb(h, s, v);
+        self.set_color(r, g, b, gamma)
+    }
+}
+
+impl<I2C, DELAY> SN3193Driver<I2C, DELAY, mode::Breathing>
+where
+    I2C: i2c::I2c,
+    DELAY: DelayNs,
+{
+    /// Set the breathing times for a particular LED. The times are set in the T0, T1, T2, T3, and T4 registers.
+    /// The same values will be assigned to all LEDs if `LEDId::ALL` is used for the `led` parameter.
+    ///
+    /// For `LEDId::ALL` the T0/T1T2/T3T4 registers are contiguous across LED1-LED3, so each
+    /// triplet is written in a single auto-incrementing transaction instead of three.
+    pub fn set_breathing_times_for_led(
+        &mut self,
+        led: LEDId,
+        intro: BreathingIntroTime,
+        ramp_up: BreathingRampUpTime,
+        hold_high: BreathingHoldHighTime,
+        ramp_down: BreathingRampDownTime,
+        hold_low: BreathingHoldLowTime,
+    ) -> Result<&mut Self, SN3193Error<I2C>> {
+        let t0_value = intro as u8;
+        let t1t2_value = (ramp_up as u8) | (hold_high as u8);
+        let t3t4_value = (ramp_down as u8) | (hold_low as u8);
+
+        if led == LEDId::ALL {
+            self.write_breathing_triplet(REGISTER_LED1_T0, t0_value, t0_value, t0_value)?;
+            self.write_breathing_triplet(REGISTER_LED1_T1T2, t1t2_value, t1t2_value, t1t2_value)?;
+            self.write_breathing_triplet(REGISTER_LED1_T3T4, t3t4_value, t3t4_value, t3t4_value)?;
+            self.state.cached_breathing_bytes = Some((t0_value, t1t2_value, t3t4_value));
+        } else {
+            if led == LEDId::LED1 {
+                self.set_breathing_register(REGISTER_LED1_T0, t0_value)?;
+                self.set_breathing_register(REGISTER_LED1_T1T2, t1t2_value)?;
+                self.set_breathing_register(REGISTER_LED1_T3T4, t3t4_value)?;
+            }
+            if led == LEDId::LED2 {
+                self.set_breathing_register(REGISTER_LED2_T0, t0_value)?;
+                self.set_breathing_register(REGISTER_LED2_T1T2, t1t2_value)?;
+                self.set_breathing_register(REGISTER_LED2_T3T4, t3t4_value)?;
+            }
+            if led == LEDId::LED3 {
+                self.set_breathing_register(REGISTER_LED3_T0, t0_value)?;
+                self.set_breathing_register(REGISTER_LED3_T1T2, t1t2_value)?;
+                self.set_breathing_register(REGISTER_LED3_T3T4, t3t4_value)?;
+            }
+        }
+        self.load_register_time_data()?;
         Ok(self)
     }
 
-    /// Set the mode of the LED, either PWM or Breathing.
-    pub fn set_led_mode(&mut self, mode: LEDModeSettings) -> Result<&mut Self, SN3193Error<I2C>> {
+    /// Immediately write the `REGISTER_TIME_UPDATE` strobe, regardless of [`SN3193Driver::set_auto_update`].
+    /// Use this to flush breathing register writes that were staged with auto-update disabled.
+    pub fn flush_breathing_times(&mut self) -> Result<&mut Self, SN3193Error<I2C>> {
         // things seem to work better with a small delay here, but it's not in the datasheet
-        self.delay.delay_ms(1);
+        self.state.delay.delay_ms(1);
+        self.state
+            .i2c
+            .write(self.state.address, &[REGISTER_TIME_UPDATE, 0xFF])
+            .map_err(SN3193Error::I2CError)?;
+        Ok(self)
+    }
+
+    /// Load the breathing time data. This is used to update the LEDs after changing the breathing times,
+    /// unless auto-update has been disabled. Private method.
+    fn load_register_time_data(&mut self) -> Result<&mut Self, SN3193Error<I2C>> {
+        if !self.state.auto_update {
+            return Ok(self);
+        }
+        self.flush_breathing_times()
+    }
+
+    /// Set a breathing register. Private method.
+    fn set_breathing_register(
+        &mut self,
+        register: u8,
+        value: u8,
+    ) -> Result<&mut Self, SN3193Error<I2C>> {
+        // things seem to work better with a small delay here, but it's not in the datasheet
+        self.state.delay.delay_ms(1);
+        self.state
+            .i2c
+            .write(self.state.address, &[register, value])
+            .map_err(SN3193Error::I2CError)?;
+        Ok(self)
+    }
+
+    /// Write the same value to three contiguous, auto-incrementing LED1/LED2/LED3 breathing
+    /// registers starting at `register` in a single transaction. Private method.
+    fn write_breathing_triplet(
+        &mut self,
+        register: u8,
+        led1: u8,
+        led2: u8,
+        led3: u8,
+    ) -> Result<&mut Self, SN3193Error<I2C>> {
+        // things seem to work better with a small delay here, but it's not in the datasheet
+        self.state.delay.delay_ms(1);
+        self.state
+            .i2c
+            .write(self.state.address, &[register, led1, led2, led3])
+            .map_err(SN3193Error::I2CError)?;
+        Ok(self)
+    }
+}
+
+impl<I2C, DELAY, MODE> SN3193Driver<I2C, DELAY, MODE>
+where
+    I2C: i2c::I2c,
+    DELAY: DelayNs,
+{
+    /// Get a mutable reference to the I2C bus used by the driver
+    pub fn i2c(&mut self) -> &mut I2C {
+        &mut self.state.i2c
+    }
+
+    /// Set the mode of the LED, either PWM or Breathing. This consumes the driver and returns it
+    /// with its `MODE` type parameter updated to match, so the methods that only make sense in
+    /// one mode (`set_pwm_levels`, `set_breathing_times_for_led`) are only callable once the chip
+    /// has actually been switched into that mode.
+    pub fn set_led_mode<NEW>(self) -> Result<SN3193Driver<I2C, DELAY, NEW>, SN3193Error<I2C>>
+    where
+        NEW: LedMode,
+    {
+        let mut this = self;
+
+        // things seem to work better with a small delay here, but it's not in the datasheet
+        this.state.delay.delay_ms(1);
+
+        this.state
+            .i2c
+            .write(
+                this.state.address,
+                &[REGISTER_LED_MODE, NEW::REGISTER_VALUE],
+            )
+            .map_err(SN3193Error::I2CError)?;
+
+        Ok(SN3193Driver {
+            state: this.state,
+            _mode: PhantomData,
+        })
+    }
 
-        self.i2c
-            .write(self.address, &[REGISTER_LED_MODE, mode as u8])
+    /// Control whether `REGISTER_DATA_UPDATE`/`REGISTER_TIME_UPDATE` are strobed automatically
+    /// after every PWM/breathing register write. Disable this when staging many changes (e.g.
+    /// writing all three LEDs' breathing times individually) to cut bus traffic, then flush once
+    /// with [`SN3193Driver::flush`] (PWM mode) or `flush_breathing_times` (breathing mode).
+    /// Auto-update is on by default.
+    pub fn set_auto_update(&mut self, enabled: bool) -> &mut Self {
+        self.state.auto_update = enabled;
+        self
+    }
+
+    /// Immediately write the `REGISTER_DATA_UPDATE` strobe, regardless of [`SN3193Driver::set_auto_update`].
+    /// Use this to flush PWM/LED-enable writes that were staged with auto-update disabled.
+    pub fn flush(&mut self) -> Result<&mut Self, SN3193Error<I2C>> {
+        // things seem to work better with a small delay here, but it's not in the datasheet
+        self.state.delay.delay_ms(1);
+        self.state
+            .i2c
+            .write(self.state.address, &[REGISTER_DATA_UPDATE, 0xFF])
             .map_err(SN3193Error::I2CError)?;
         Ok(self)
     }
@@ -337,11 +677,16 @@ where
     /// Set the current for the LEDs.
     pub fn set_current(&mut self, current: CurrentSettings) -> Result<&mut Self, SN3193Error<I2C>> {
         // things seem to work better with a small delay here, but it's not in the datasheet
-        self.delay.delay_ms(1);
+        self.state.delay.delay_ms(1);
 
-        self.i2c
-            .write(self.address, &[REGISTER_CURRENT_SETTING, current as u8])
+        self.state
+            .i2c
+            .write(
+                self.state.address,
+                &[REGISTER_CURRENT_SETTING, current as u8],
+            )
             .map_err(SN3193Error::I2CError)?;
+        self.state.cached_current = Some(current);
         Ok(self)
     }
 
@@ -353,7 +698,7 @@ where
         led3: bool,
     ) -> Result<&mut Self, SN3193Error<I2C>> {
         // things seem to work better with a small delay here, but it's not in the datasheet
-        self.delay.delay_ms(1);
+        self.state.delay.delay_ms(1);
 
         let mut led_enable = 0;
         if led1 {
@@ -365,103 +710,113 @@ where
         if led3 {
             led_enable |= 0b100;
         }
-        self.i2c
-            .write(self.address, &[REGISTER_LED_CONTROL, led_enable])
-            .map_err(SN3193Error::I2CError)?;
-        self.load_register_data()
-    }
-
-    /// Set the PWM levels for the RGB LED. 255 is full on, 0 is off.
-    pub fn set_pwm_levels(
-        &mut self,
-        led1: u8,
-        led2: u8,
-        led3: u8,
-    ) -> Result<&mut Self, SN3193Error<I2C>> {
-        // things seem to work better with a small delay here, but it's not in the datasheet
-        self.delay.delay_ms(1);
-        self.i2c
-            .write(self.address, &[REGISTER_LED1_PWM, led1])
-            .map_err(SN3193Error::I2CError)?;
-        // things seem to work better with a small delay here, but it's not in the datasheet
-        self.delay.delay_ms(1);
-        self.i2c
-            .write(self.address, &[REGISTER_LED2_PWM, led2])
-            .map_err(SN3193Error::I2CError)?;
-        // things seem to work better with a small delay here, but it's not in the datasheet
-        self.delay.delay_ms(1);
-        self.i2c
-            .write(self.address, &[REGISTER_LED3_PWM, led3])
+        self.state
+            .i2c
+            .write(self.state.address, &[REGISTER_LED_CONTROL, led_enable])
             .map_err(SN3193Error::I2CError)?;
+        self.state.cached_led_enable = Some((led1, led2, led3));
         self.load_register_data()
     }
 
-    /// Set the breathing times for a particular LED. The times are set in the T0, T1, T2, T3, and T4 registers.
-    /// The same values will be assigned to all LEDs if `LEDId::ALL` is used for the `led` parameter.
-    pub fn set_breathing_times_for_led(
-        &mut self,
-        led: LEDId,
-        intro: BreathingIntroTime,
-        ramp_up: BreathingRampUpTime,
-        hold_high: BreathingHoldHighTime,
-        ramp_down: BreathingRampDownTime,
-        hold_low: BreathingHoldLowTime,
-    ) -> Result<&mut Self, SN3193Error<I2C>> {
-        let t0_value = intro as u8;
-        let t1t2_value = (ramp_up as u8) | (hold_high as u8);
-        let t3t4_value = (ramp_down as u8) | (hold_low as u8);
-
-        if led == LEDId::LED1 || led == LEDId::ALL {
-            self.set_breathing_register(REGISTER_LED1_T0, t0_value)?;
-            self.set_breathing_register(REGISTER_LED1_T1T2, t1t2_value)?;
-            self.set_breathing_register(REGISTER_LED1_T3T4, t3t4_value)?;
-        }
-        if led == LEDId::LED2 || led == LEDId::ALL {
-            self.set_breathing_register(REGISTER_LED2_T0, t0_value)?;
-            self.set_breathing_register(REGISTER_LED2_T1T2, t1t2_value)?;
-            self.set_breathing_register(REGISTER_LED2_T3T4, t3t4_value)?;
-        }
-        if led == LEDId::LED3 || led == LEDId::ALL {
-            self.set_breathing_register(REGISTER_LED3_T0, t0_value)?;
-            self.set_breathing_register(REGISTER_LED3_T1T2, t1t2_value)?;
-            self.set_breathing_register(REGISTER_LED3_T3T4, t3t4_value)?;
+    /// Load the register data. This is used to update the LEDs after changing the PWM levels,
+    /// unless auto-update has been disabled. Private method.
+    fn load_register_data(&mut self) -> Result<&mut Self, SN3193Error<I2C>> {
+        if !self.state.auto_update {
+            return Ok(self);
         }
-        self.load_register_time_data()?;
-        Ok(self)
+        self.flush()
     }
 
-    /// Load the register data. This is used to update the LEDs after changing the PWM levels. Private method.
-    fn load_register_data(&mut self) -> Result<&mut Self, SN3193Error<I2C>> {
+    /// Put the chip into software shutdown with all channels disabled, to save current while the
+    /// LEDs aren't needed. The current/LED-enable/PWM/whole-strip-breathing configuration is
+    /// preserved and reapplied by [`SN3193Driver::wake`].
+    pub fn sleep(&mut self) -> Result<&mut Self, SN3193Error<I2C>> {
         // things seem to work better with a small delay here, but it's not in the datasheet
-        self.delay.delay_ms(1);
-        self.i2c
-            .write(self.address, &[REGISTER_DATA_UPDATE, 0xFF])
+        self.state.delay.delay_ms(1);
+        self.state
+            .i2c
+            .write(
+                self.state.address,
+                &[
+                    REGISTER_SHUTDOWN,
+                    SHUTDOWN_CHANNEL_DISABLE | SOFTWARE_SHUTDOWN_MODE,
+                ],
+            )
             .map_err(SN3193Error::I2CError)?;
         Ok(self)
     }
 
-    /// Load the breathing time data. This is used to update the LEDs after changing the breathing times. Private method.
-    fn load_register_time_data(&mut self) -> Result<&mut Self, SN3193Error<I2C>> {
+    /// Wake the chip from [`SN3193Driver::sleep`], restoring normal operation and re-applying
+    /// whatever current/LED-enable/PWM/whole-strip-breathing configuration was last set.
+    pub fn wake(&mut self) -> Result<&mut Self, SN3193Error<I2C>> {
         // things seem to work better with a small delay here, but it's not in the datasheet
-        self.delay.delay_ms(1);
-        self.i2c
-            .write(self.address, &[REGISTER_TIME_UPDATE, 0xFF])
+        self.state.delay.delay_ms(1);
+        self.state
+            .i2c
+            .write(
+                self.state.address,
+                &[
+                    REGISTER_SHUTDOWN,
+                    SHUTDOWN_CHANNEL_ENABLE | SOFTWARE_SHUTDOWN_NORMAL,
+                ],
+            )
             .map_err(SN3193Error::I2CError)?;
+
+        if let Some(current) = self.state.cached_current {
+            self.set_current(current)?;
+        }
+        if let Some((led1, led2, led3)) = self.state.cached_led_enable {
+            self.enable_leds(led1, led2, led3)?;
+        }
+        if let Some((r, g, b)) = self.state.cached_pwm_levels {
+            self.state.delay.delay_ms(1);
+            self.state
+                .i2c
+                .write(self.state.address, &[REGISTER_LED1_PWM, r, g, b])
+                .map_err(SN3193Error::I2CError)?;
+            self.load_register_data()?;
+        }
+        if let Some((t0, t1t2, t3t4)) = self.state.cached_breathing_bytes {
+            self.state.delay.delay_ms(1);
+            self.state
+                .i2c
+                .write(self.state.address, &[REGISTER_LED1_T0, t0, t0, t0])
+                .map_err(SN3193Error::I2CError)?;
+            self.state.delay.delay_ms(1);
+            self.state
+                .i2c
+                .write(self.state.address, &[REGISTER_LED1_T1T2, t1t2, t1t2, t1t2])
+                .map_err(SN3193Error::I2CError)?;
+            self.state.delay.delay_ms(1);
+            self.state
+                .i2c
+                .write(self.state.address, &[REGISTER_LED1_T3T4, t3t4, t3t4, t3t4])
+                .map_err(SN3193Error::I2CError)?;
+            if self.state.auto_update {
+                self.state.delay.delay_ms(1);
+                self.state
+                    .i2c
+                    .write(self.state.address, &[REGISTER_TIME_UPDATE, 0xFF])
+                    .map_err(SN3193Error::I2CError)?;
+            }
+        }
+
         Ok(self)
     }
+}
 
-    /// Set a breathing register. Private method.
-    fn set_breathing_register(
-        &mut self,
-        register: u8,
-        value: u8,
-    ) -> Result<&mut Self, SN3193Error<I2C>> {
-        // things seem to work better with a small delay here, but it's not in the datasheet
+impl<I2C, DELAY> Drop for DriverState<I2C, DELAY>
+where
+    I2C: i2c::I2c,
+    DELAY: DelayNs,
+{
+    /// Turn the LED channels off so a dropped driver leaves the LEDs dark. `Drop` can't return a
+    /// `Result`, so any I2C error here is silently ignored - this is a best-effort quiesce.
+    fn drop(&mut self) {
         self.delay.delay_ms(1);
-        self.i2c
-            .write(self.address, &[register, value])
-            .map_err(SN3193Error::I2CError)?;
-        Ok(self)
+        let _ = self.i2c.write(self.address, &[REGISTER_LED_CONTROL, 0b000]);
+        self.delay.delay_ms(1);
+        let _ = self.i2c.write(self.address, &[REGISTER_DATA_UPDATE, 0xFF]);
     }
 }
 
@@ -484,21 +839,85 @@ mod tests {
     }
 
     #[test]
-    fn test_led_mode_settings_into() {
-        assert_eq!(LEDModeSettings::PWM as u8, 0b00_0_00000);
-        assert_eq!(LEDModeSettings::Breathing as u8, 0b00_1_00000);
+    fn test_address_bits() {
+        assert_eq!(Address::Gnd.bits(), 0x68);
+        assert_eq!(Address::Scl.bits(), 0x69);
+        assert_eq!(Address::Sda.bits(), 0x6A);
+        assert_eq!(Address::Vdd.bits(), 0x6B);
+        assert_eq!(u8::from(Address::Gnd), 0x68);
+    }
+
+    #[test]
+    fn test_led_mode_register_values() {
+        assert_eq!(<mode::Pwm as LedMode>::REGISTER_VALUE, 0b00_0_00000);
+        assert_eq!(<mode::Breathing as LedMode>::REGISTER_VALUE, 0b00_1_00000);
+    }
+
+    /// The drop-time quiesce writes appended to every test's expectations, since a driver built in
+    /// a test is dropped - and thus quiesced - at the end of that test's scope.
+    fn drop_quiesce_expectations(address: u8) -> [I2cTransaction; 2] {
+        [
+            I2cTransaction::write(address, std::vec![REGISTER_LED_CONTROL, 0b000]),
+            I2cTransaction::write(address, std::vec![REGISTER_DATA_UPDATE, 0xFF]),
+        ]
+    }
+
+    #[test]
+    fn test_init_enters_normal_operation_and_lights_leds() {
+        let expectations = [
+            I2cTransaction::write(0x68, std::vec![REGISTER_RESET]),
+            I2cTransaction::write(
+                0x68,
+                std::vec![
+                    REGISTER_SHUTDOWN,
+                    SHUTDOWN_CHANNEL_ENABLE | SOFTWARE_SHUTDOWN_NORMAL
+                ],
+            ),
+            I2cTransaction::write(
+                0x68,
+                std::vec![REGISTER_LED_MODE, <mode::Pwm as LedMode>::REGISTER_VALUE],
+            ),
+            I2cTransaction::write(
+                0x68,
+                std::vec![
+                    REGISTER_CURRENT_SETTING,
+                    CurrentSettings::Current17p5mA as u8
+                ],
+            ),
+            I2cTransaction::write(0x68, std::vec![REGISTER_LED_CONTROL, 0b111]),
+            I2cTransaction::write(0x68, std::vec![REGISTER_DATA_UPDATE, 0xFF]),
+            drop_quiesce_expectations(0x68)[0].clone(),
+            drop_quiesce_expectations(0x68)[1].clone(),
+        ];
+        let i2c = I2cMock::new(&expectations);
+        let mut i2c_check = i2c.clone();
+        {
+            let driver = SN3193Driver::new(i2c, NoopDelay);
+            let _driver = driver.init().unwrap();
+        }
+        i2c_check.done();
     }
 
     #[test]
     fn test_set_led_mode() {
-        let expectations = [I2cTransaction::write(
-            0x68,
-            std::vec![REGISTER_LED_MODE, LEDModeSettings::PWM as u8],
-        )];
+        let expectations = [
+            I2cTransaction::write(
+                0x68,
+                std::vec![
+                    REGISTER_LED_MODE,
+                    <mode::Breathing as LedMode>::REGISTER_VALUE
+                ],
+            ),
+            drop_quiesce_expectations(0x68)[0].clone(),
+            drop_quiesce_expectations(0x68)[1].clone(),
+        ];
         let i2c = I2cMock::new(&expectations);
-        let mut driver = SN3193Driver::new(i2c, NoopDelay);
-        driver.set_led_mode(LEDModeSettings::PWM).unwrap();
-        driver.i2c().done();
+        let mut i2c_check = i2c.clone();
+        {
+            let driver = SN3193Driver::new(i2c, NoopDelay);
+            let _driver = driver.set_led_mode::<mode::Breathing>().unwrap();
+        }
+        i2c_check.done();
     }
 
     #[test]
@@ -515,12 +934,17 @@ mod tests {
                 0x68,
                 std::vec![REGISTER_CURRENT_SETTING, CurrentSettings::Current42mA as u8],
             ),
+            drop_quiesce_expectations(0x68)[0].clone(),
+            drop_quiesce_expectations(0x68)[1].clone(),
         ];
         let i2c = I2cMock::new(&expectations);
-        let mut driver = SN3193Driver::new(i2c, NoopDelay);
-        assert!(driver.set_current(CurrentSettings::Current17p5mA).is_ok());
-        assert!(driver.set_current(CurrentSettings::Current42mA).is_ok());
-        driver.i2c().done();
+        let mut i2c_check = i2c.clone();
+        {
+            let mut driver = SN3193Driver::new(i2c, NoopDelay);
+            assert!(driver.set_current(CurrentSettings::Current17p5mA).is_ok());
+            assert!(driver.set_current(CurrentSettings::Current42mA).is_ok());
+        }
+        i2c_check.done();
     }
 
     #[test]
@@ -532,26 +956,323 @@ mod tests {
             I2cTransaction::write(0x68, std::vec![REGISTER_DATA_UPDATE, 0xFF]),
             I2cTransaction::write(0x68, std::vec![REGISTER_LED_CONTROL, 0b011]),
             I2cTransaction::write(0x68, std::vec![REGISTER_DATA_UPDATE, 0xFF]),
+            drop_quiesce_expectations(0x68)[0].clone(),
+            drop_quiesce_expectations(0x68)[1].clone(),
         ];
         let i2c = I2cMock::new(&expectations);
-        let mut driver = SN3193Driver::new(i2c, NoopDelay);
-        assert!(driver.enable_leds(true, true, true).is_ok());
-        assert!(driver.enable_leds(true, false, true).is_ok());
-        assert!(driver.enable_leds(true, true, false).is_ok());
-        driver.i2c().done();
+        let mut i2c_check = i2c.clone();
+        {
+            let mut driver = SN3193Driver::new(i2c, NoopDelay);
+            assert!(driver.enable_leds(true, true, true).is_ok());
+            assert!(driver.enable_leds(true, false, true).is_ok());
+            assert!(driver.enable_leds(true, true, false).is_ok());
+        }
+        i2c_check.done();
     }
 
     #[test]
     fn test_set_pwm_levels() {
         let expectations = [
-            I2cTransaction::write(0x6B, std::vec![REGISTER_LED1_PWM, 255]),
-            I2cTransaction::write(0x6B, std::vec![REGISTER_LED2_PWM, 128]),
-            I2cTransaction::write(0x6B, std::vec![REGISTER_LED3_PWM, 0]),
+            I2cTransaction::write(0x6B, std::vec![REGISTER_LED1_PWM, 255, 128, 0]),
             I2cTransaction::write(0x6B, std::vec![REGISTER_DATA_UPDATE, 0xFF]),
+            drop_quiesce_expectations(0x6B)[0].clone(),
+            drop_quiesce_expectations(0x6B)[1].clone(),
+        ];
+        let i2c = I2cMock::new(&expectations);
+        let mut i2c_check = i2c.clone();
+        {
+            let mut driver = SN3193Driver::new_with_address(i2c, NoopDelay, Address::Vdd);
+            assert!(driver.set_pwm_levels(255, 128, 0).is_ok());
+        }
+        i2c_check.done();
+    }
+
+    #[test]
+    fn test_set_color_raw_passes_values_through() {
+        let expectations = [
+            I2cTransaction::write(0x68, std::vec![REGISTER_LED1_PWM, 255, 128, 0]),
+            I2cTransaction::write(0x68, std::vec![REGISTER_DATA_UPDATE, 0xFF]),
+            drop_quiesce_expectations(0x68)[0].clone(),
+            drop_quiesce_expectations(0x68)[1].clone(),
+        ];
+        let i2c = I2cMock::new(&expectations);
+        let mut i2c_check = i2c.clone();
+        {
+            let mut driver = SN3193Driver::new(i2c, NoopDelay);
+            assert!(driver.set_color(255, 128, 0, GammaCorrection::Raw).is_ok());
+        }
+        i2c_check.done();
+    }
+
+    #[test]
+    fn test_set_color_applies_gamma_correction() {
+        let expectations = [
+            I2cTransaction::write(0x68, std::vec![REGISTER_LED1_PWM, 255, 47, 0]),
+            I2cTransaction::write(0x68, std::vec![REGISTER_DATA_UPDATE, 0xFF]),
+            drop_quiesce_expectations(0x68)[0].clone(),
+            drop_quiesce_expectations(0x68)[1].clone(),
+        ];
+        let i2c = I2cMock::new(&expectations);
+        let mut i2c_check = i2c.clone();
+        {
+            let mut driver = SN3193Driver::new(i2c, NoopDelay);
+            assert!(driver
+                .set_color(255, 128, 0, GammaCorrection::Corrected)
+                .is_ok());
+        }
+        i2c_check.done();
+    }
+
+    #[test]
+    fn test_sleep_and_wake_restore_cached_state() {
+        let expectations = [
+            I2cTransaction::write(
+                0x68,
+                std::vec![
+                    REGISTER_CURRENT_SETTING,
+                    CurrentSettings::Current17p5mA as u8
+                ],
+            ),
+            I2cTransaction::write(0x68, std::vec![REGISTER_LED_CONTROL, 0b111]),
+            I2cTransaction::write(0x68, std::vec![REGISTER_DATA_UPDATE, 0xFF]),
+            I2cTransaction::write(0x68, std::vec![REGISTER_LED1_PWM, 255, 128, 0]),
+            I2cTransaction::write(0x68, std::vec![REGISTER_DATA_UPDATE, 0xFF]),
+            I2cTransaction::write(
+                0x68,
+                std::vec![
+                    REGISTER_SHUTDOWN,
+                    SHUTDOWN_CHANNEL_DISABLE | SOFTWARE_SHUTDOWN_MODE
+                ],
+            ),
+            I2cTransaction::write(
+                0x68,
+                std::vec![
+                    REGISTER_SHUTDOWN,
+                    SHUTDOWN_CHANNEL_ENABLE | SOFTWARE_SHUTDOWN_NORMAL
+                ],
+            ),
+            I2cTransaction::write(
+                0x68,
+                std::vec![
+                    REGISTER_CURRENT_SETTING,
+                    CurrentSettings::Current17p5mA as u8
+                ],
+            ),
+            I2cTransaction::write(0x68, std::vec![REGISTER_LED_CONTROL, 0b111]),
+            I2cTransaction::write(0x68, std::vec![REGISTER_DATA_UPDATE, 0xFF]),
+            I2cTransaction::write(0x68, std::vec![REGISTER_LED1_PWM, 255, 128, 0]),
+            I2cTransaction::write(0x68, std::vec![REGISTER_DATA_UPDATE, 0xFF]),
+            drop_quiesce_expectations(0x68)[0].clone(),
+            drop_quiesce_expectations(0x68)[1].clone(),
+        ];
+        let i2c = I2cMock::new(&expectations);
+        let mut i2c_check = i2c.clone();
+        {
+            let mut driver = SN3193Driver::new(i2c, NoopDelay);
+            driver.set_current(CurrentSettings::Current17p5mA).unwrap();
+            driver.enable_leds(true, true, true).unwrap();
+            driver.set_pwm_levels(255, 128, 0).unwrap();
+
+            driver.sleep().unwrap();
+            driver.wake().unwrap();
+        }
+        i2c_check.done();
+    }
+
+    #[test]
+    fn test_wake_respects_auto_update_for_breathing_restore() {
+        let expectations = [
+            I2cTransaction::write(
+                0x68,
+                std::vec![
+                    REGISTER_LED_MODE,
+                    <mode::Breathing as LedMode>::REGISTER_VALUE
+                ],
+            ),
+            I2cTransaction::write(
+                0x68,
+                std::vec![
+                    REGISTER_LED1_T0,
+                    BreathingIntroTime::Time1p04s as u8,
+                    BreathingIntroTime::Time1p04s as u8,
+                    BreathingIntroTime::Time1p04s as u8,
+                ],
+            ),
+            I2cTransaction::write(
+                0x68,
+                std::vec![
+                    REGISTER_LED1_T1T2,
+                    BreathingRampUpTime::Time4p16s as u8 | BreathingHoldHighTime::Time1p04s as u8,
+                    BreathingRampUpTime::Time4p16s as u8 | BreathingHoldHighTime::Time1p04s as u8,
+                    BreathingRampUpTime::Time4p16s as u8 | BreathingHoldHighTime::Time1p04s as u8,
+                ],
+            ),
+            I2cTransaction::write(
+                0x68,
+                std::vec![
+                    REGISTER_LED1_T3T4,
+                    BreathingRampDownTime::Time4p16s as u8 | BreathingHoldLowTime::Time2p08s as u8,
+                    BreathingRampDownTime::Time4p16s as u8 | BreathingHoldLowTime::Time2p08s as u8,
+                    BreathingRampDownTime::Time4p16s as u8 | BreathingHoldLowTime::Time2p08s as u8,
+                ],
+            ),
+            // no REGISTER_TIME_UPDATE strobe here - auto-update is disabled.
+            I2cTransaction::write(
+                0x68,
+                std::vec![
+                    REGISTER_SHUTDOWN,
+                    SHUTDOWN_CHANNEL_DISABLE | SOFTWARE_SHUTDOWN_MODE
+                ],
+            ),
+            I2cTransaction::write(
+                0x68,
+                std::vec![
+                    REGISTER_SHUTDOWN,
+                    SHUTDOWN_CHANNEL_ENABLE | SOFTWARE_SHUTDOWN_NORMAL
+                ],
+            ),
+            I2cTransaction::write(
+                0x68,
+                std::vec![
+                    REGISTER_LED1_T0,
+                    BreathingIntroTime::Time1p04s as u8,
+                    BreathingIntroTime::Time1p04s as u8,
+                    BreathingIntroTime::Time1p04s as u8,
+                ],
+            ),
+            I2cTransaction::write(
+                0x68,
+                std::vec![
+                    REGISTER_LED1_T1T2,
+                    BreathingRampUpTime::Time4p16s as u8 | BreathingHoldHighTime::Time1p04s as u8,
+                    BreathingRampUpTime::Time4p16s as u8 | BreathingHoldHighTime::Time1p04s as u8,
+                    BreathingRampUpTime::Time4p16s as u8 | BreathingHoldHighTime::Time1p04s as u8,
+                ],
+            ),
+            I2cTransaction::write(
+                0x68,
+                std::vec![
+                    REGISTER_LED1_T3T4,
+                    BreathingRampDownTime::Time4p16s as u8 | BreathingHoldLowTime::Time2p08s as u8,
+                    BreathingRampDownTime::Time4p16s as u8 | BreathingHoldLowTime::Time2p08s as u8,
+                    BreathingRampDownTime::Time4p16s as u8 | BreathingHoldLowTime::Time2p08s as u8,
+                ],
+            ),
+            // again, no REGISTER_TIME_UPDATE strobe - wake() must respect auto-update just like
+            // the PWM restore path does.
+            drop_quiesce_expectations(0x68)[0].clone(),
+            drop_quiesce_expectations(0x68)[1].clone(),
+        ];
+        let i2c = I2cMock::new(&expectations);
+        let mut i2c_check = i2c.clone();
+        {
+            let driver = SN3193Driver::new(i2c, NoopDelay);
+            let mut driver = driver.set_led_mode::<mode::Breathing>().unwrap();
+            driver.set_auto_update(false);
+            driver
+                .set_breathing_times_for_led(
+                    LEDId::ALL,
+                    BreathingIntroTime::Time1p04s,
+                    BreathingRampUpTime::Time4p16s,
+                    BreathingHoldHighTime::Time1p04s,
+                    BreathingRampDownTime::Time4p16s,
+                    BreathingHoldLowTime::Time2p08s,
+                )
+                .unwrap();
+
+            driver.sleep().unwrap();
+            driver.wake().unwrap();
+        }
+        i2c_check.done();
+    }
+
+    #[test]
+    fn test_drop_quiesces_led_channels() {
+        let expectations = drop_quiesce_expectations(0x68);
+        let i2c = I2cMock::new(&expectations);
+        let mut i2c_check = i2c.clone();
+        {
+            let _driver = SN3193Driver::new(i2c, NoopDelay);
+        }
+        i2c_check.done();
+    }
+
+    #[test]
+    fn test_set_breathing_times_for_led_all_batches_writes() {
+        let expectations = [
+            I2cTransaction::write(
+                0x68,
+                std::vec![
+                    REGISTER_LED_MODE,
+                    <mode::Breathing as LedMode>::REGISTER_VALUE
+                ],
+            ),
+            I2cTransaction::write(
+                0x68,
+                std::vec![
+                    REGISTER_LED1_T0,
+                    BreathingIntroTime::Time1p04s as u8,
+                    BreathingIntroTime::Time1p04s as u8,
+                    BreathingIntroTime::Time1p04s as u8,
+                ],
+            ),
+            I2cTransaction::write(
+                0x68,
+                std::vec![
+                    REGISTER_LED1_T1T2,
+                    BreathingRampUpTime::Time4p16s as u8 | BreathingHoldHighTime::Time1p04s as u8,
+                    BreathingRampUpTime::Time4p16s as u8 | BreathingHoldHighTime::Time1p04s as u8,
+                    BreathingRampUpTime::Time4p16s as u8 | BreathingHoldHighTime::Time1p04s as u8,
+                ],
+            ),
+            I2cTransaction::write(
+                0x68,
+                std::vec![
+                    REGISTER_LED1_T3T4,
+                    BreathingRampDownTime::Time4p16s as u8 | BreathingHoldLowTime::Time2p08s as u8,
+                    BreathingRampDownTime::Time4p16s as u8 | BreathingHoldLowTime::Time2p08s as u8,
+                    BreathingRampDownTime::Time4p16s as u8 | BreathingHoldLowTime::Time2p08s as u8,
+                ],
+            ),
+            I2cTransaction::write(0x68, std::vec![REGISTER_TIME_UPDATE, 0xFF]),
+            drop_quiesce_expectations(0x68)[0].clone(),
+            drop_quiesce_expectations(0x68)[1].clone(),
         ];
         let i2c = I2cMock::new(&expectations);
-        let mut driver = SN3193Driver::new_with_address(i2c, NoopDelay, 0x6B);
-        assert!(driver.set_pwm_levels(255, 128, 0).is_ok());
-        driver.i2c().done();
+        let mut i2c_check = i2c.clone();
+        {
+            let driver = SN3193Driver::new(i2c, NoopDelay);
+            let mut driver = driver.set_led_mode::<mode::Breathing>().unwrap();
+            assert!(driver
+                .set_breathing_times_for_led(
+                    LEDId::ALL,
+                    BreathingIntroTime::Time1p04s,
+                    BreathingRampUpTime::Time4p16s,
+                    BreathingHoldHighTime::Time1p04s,
+                    BreathingRampDownTime::Time4p16s,
+                    BreathingHoldLowTime::Time2p08s,
+                )
+                .is_ok());
+        }
+        i2c_check.done();
+    }
+
+    #[test]
+    fn test_set_auto_update_suppresses_strobe_until_flushed() {
+        let expectations = [
+            I2cTransaction::write(0x68, std::vec![REGISTER_LED1_PWM, 255, 128, 0]),
+            I2cTransaction::write(0x68, std::vec![REGISTER_DATA_UPDATE, 0xFF]),
+            drop_quiesce_expectations(0x68)[0].clone(),
+            drop_quiesce_expectations(0x68)[1].clone(),
+        ];
+        let i2c = I2cMock::new(&expectations);
+        let mut i2c_check = i2c.clone();
+        {
+            let mut driver = SN3193Driver::new(i2c, NoopDelay);
+            driver.set_auto_update(false);
+            assert!(driver.set_pwm_levels(255, 128, 0).is_ok());
+            assert!(driver.flush().is_ok());
+        }
+        i2c_check.done();
     }
 }
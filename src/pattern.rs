@@ -0,0 +1,234 @@
+//! Software-driven color pattern playback.
+//!
+//! Unlike the SN3193's built-in breathing mode, a [`Pattern`] is a sequence of keyframes that is
+//! interpolated in software and written out with `set_pwm_levels`. This allows arbitrary color
+//! transitions - fades, heartbeat pulses, rainbow cycling, and so on - without relying on the
+//! chip's fixed breathing timers. [`PatternPlayer`] does no I2C or delay work itself: call
+//! [`PatternPlayer::step`] with the elapsed time from a main loop, and write the returned levels
+//! out with `SN3193Driver::set_pwm_levels` (or `set_color`) when it returns `Some`.
+
+use core::cmp::max;
+
+/// A single point in a [`Pattern`]: the RGB color to reach, and how long (in milliseconds) it
+/// takes to transition there from the previous keyframe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Keyframe {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub duration_ms: u32,
+}
+
+impl Keyframe {
+    /// Create a new keyframe.
+    pub fn new(r: u8, g: u8, b: u8, duration_ms: u32) -> Self {
+        Self {
+            r,
+            g,
+            b,
+            duration_ms,
+        }
+    }
+}
+
+/// A sequence of [`Keyframe`]s describing a color animation.
+#[derive(Debug, Clone, Copy)]
+pub struct Pattern<'a> {
+    keyframes: &'a [Keyframe],
+    looping: bool,
+}
+
+impl<'a> Pattern<'a> {
+    /// Create a pattern from `keyframes`. When `looping` is `true`, playback wraps back to the
+    /// first keyframe after the last one is reached instead of holding there forever.
+    pub fn new(keyframes: &'a [Keyframe], looping: bool) -> Self {
+        Self { keyframes, looping }
+    }
+}
+
+/// Advances a [`Pattern`] over time and reports the interpolated RGB levels.
+///
+/// `step` linearly interpolates each channel between the current and next keyframe based on
+/// accumulated time, and only returns `Some` when the interpolated levels actually changed since
+/// the last call - so a caller can poll it every tick and only write to the chip when needed.
+#[derive(Debug, Clone, Copy)]
+pub struct PatternPlayer<'a> {
+    pattern: Pattern<'a>,
+    index: usize,
+    elapsed_ms: u32,
+    last_levels: Option<(u8, u8, u8)>,
+    finished: bool,
+}
+
+impl<'a> PatternPlayer<'a> {
+    /// Create a new player, starting at the first keyframe of `pattern`.
+    pub fn new(pattern: Pattern<'a>) -> Self {
+        Self {
+            pattern,
+            index: 0,
+            elapsed_ms: 0,
+            last_levels: None,
+            finished: false,
+        }
+    }
+
+    /// Advance playback by `elapsed_ms` and return the interpolated `(r, g, b)` levels if they
+    /// changed since the last call. Returns `None` once a non-looping pattern has finished, or if
+    /// the levels have not changed.
+    pub fn step(&mut self, elapsed_ms: u32) -> Option<(u8, u8, u8)> {
+        let keyframes = self.pattern.keyframes;
+        if keyframes.is_empty() || self.finished {
+            return None;
+        }
+        if keyframes.len() == 1 {
+            let only = keyframes[0];
+            return self.emit(only.r, only.g, only.b);
+        }
+
+        self.elapsed_ms += elapsed_ms;
+
+        loop {
+            let next_index = self.index + 1;
+            let next = if next_index < keyframes.len() {
+                keyframes[next_index]
+            } else if self.pattern.looping {
+                keyframes[0]
+            } else {
+                self.finished = true;
+                let last = keyframes[self.index];
+                return self.emit(last.r, last.g, last.b);
+            };
+
+            // Guard against a zero duration keyframe stalling the loop forever.
+            let duration = max(next.duration_ms, 1);
+            if self.elapsed_ms < duration {
+                let from = keyframes[self.index];
+                let r = lerp(from.r, next.r, self.elapsed_ms, duration);
+                let g = lerp(from.g, next.g, self.elapsed_ms, duration);
+                let b = lerp(from.b, next.b, self.elapsed_ms, duration);
+                return self.emit(r, g, b);
+            }
+
+            self.elapsed_ms -= duration;
+            self.index = if next_index < keyframes.len() {
+                next_index
+            } else {
+                0
+            };
+        }
+    }
+
+    fn emit(&mut self, r: u8, g: u8, b: u8) -> Option<(u8, u8, u8)> {
+        let levels = (r, g, b);
+        if self.last_levels == Some(levels) {
+            None
+        } else {
+            self.last_levels = Some(levels);
+            Some(levels)
+        }
+    }
+}
+
+/// Linearly interpolate a single channel `t` milliseconds into a `duration`-millisecond segment.
+///
+/// The arithmetic is done in `i64` (rather than `i32`) so that a `duration_ms`/`t` anywhere in the
+/// full `u32` range - a `Keyframe::duration_ms` isn't bounded to fit in `i32` - can't overflow the
+/// multiplication below.
+fn lerp(from: u8, to: u8, t: u32, duration: u32) -> u8 {
+    let from = from as i64;
+    let to = to as i64;
+    let t = t.min(duration) as i64;
+    let duration = duration as i64;
+    let value = from + (to - from) * t / duration;
+    value.clamp(0, 255) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_single_keyframe_holds() {
+        let keyframes = [Keyframe::new(10, 20, 30, 0)];
+        let pattern = Pattern::new(&keyframes, false);
+        let mut player = PatternPlayer::new(pattern);
+
+        assert_eq!(player.step(0), Some((10, 20, 30)));
+        assert_eq!(player.step(100), None);
+    }
+
+    #[test]
+    fn test_linear_interpolation() {
+        let keyframes = [
+            Keyframe::new(0, 0, 0, 0),
+            Keyframe::new(100, 200, 255, 1000),
+        ];
+        let pattern = Pattern::new(&keyframes, false);
+        let mut player = PatternPlayer::new(pattern);
+
+        assert_eq!(player.step(0), Some((0, 0, 0)));
+        assert_eq!(player.step(500), Some((50, 100, 127)));
+        assert_eq!(player.step(500), Some((100, 200, 255)));
+    }
+
+    #[test]
+    fn test_non_looping_pattern_holds_final_keyframe() {
+        let keyframes = [Keyframe::new(0, 0, 0, 0), Keyframe::new(255, 0, 0, 100)];
+        let pattern = Pattern::new(&keyframes, false);
+        let mut player = PatternPlayer::new(pattern);
+
+        assert_eq!(player.step(100), Some((255, 0, 0)));
+        assert_eq!(player.step(1000), None);
+    }
+
+    #[test]
+    fn test_looping_pattern_wraps() {
+        let keyframes = [Keyframe::new(0, 0, 0, 0), Keyframe::new(255, 0, 0, 100)];
+        let pattern = Pattern::new(&keyframes, true);
+        let mut player = PatternPlayer::new(pattern);
+
+        assert_eq!(player.step(100), Some((255, 0, 0)));
+        // Having wrapped back to the start keyframe, it begins interpolating towards the next one again.
+        assert_eq!(player.step(100), Some((252, 0, 0)));
+    }
+
+    #[test]
+    fn test_step_returns_none_when_unchanged() {
+        let keyframes = [Keyframe::new(10, 10, 10, 1000)];
+        let pattern = Pattern::new(&keyframes, false);
+        let mut player = PatternPlayer::new(pattern);
+
+        assert_eq!(player.step(0), Some((10, 10, 10)));
+        assert_eq!(player.step(10), None);
+    }
+
+    #[test]
+    fn test_zero_duration_mid_pattern_keyframe_does_not_stall() {
+        // The `max(next.duration_ms, 1)` guard in `step` treats the middle keyframe's zero duration
+        // as 1 ms, so it's passed after 1 ms and the remaining 49 ms fall into the last segment:
+        // 100 + (200 - 100) * 49 / 100 = 149.
+        let keyframes = [
+            Keyframe::new(0, 0, 0, 0),
+            Keyframe::new(100, 0, 0, 0),
+            Keyframe::new(200, 0, 0, 100),
+        ];
+        let pattern = Pattern::new(&keyframes, false);
+        let mut player = PatternPlayer::new(pattern);
+
+        assert_eq!(player.step(50), Some((149, 0, 0)));
+    }
+
+    #[test]
+    fn test_lerp_with_multi_day_duration_does_not_overflow() {
+        // `duration_ms` is a `u32`, so durations beyond `i32::MAX` (~24.8 days) are legal; the
+        // interpolation must not wrap around and produce garbage for them.
+        let keyframes = [
+            Keyframe::new(0, 0, 0, 0),
+            Keyframe::new(255, 0, 0, 3_000_000_000),
+        ];
+        let pattern = Pattern::new(&keyframes, false);
+        let mut player = PatternPlayer::new(pattern);
+
+        assert_eq!(player.step(1_500_000_000), Some((127, 0, 0)));
+    }
+}